@@ -1,10 +1,13 @@
-use std::io::{Error, ErrorKind};
+use std::io::Error;
 
 #[derive(Debug, Error)]
 pub enum CRHError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("incorrect bit length: {} is not a multiple of 8", _0)]
+    IncorrectBitLength(usize),
+
     #[error("{}", _0)]
     Message(String),
 }
@@ -17,6 +20,6 @@ impl From<Error> for CRHError {
 
 impl From<CRHError> for Error {
     fn from(error: CRHError) -> Error {
-        Error::new(ErrorKind::Other, error.to_string())
+        Error::other(error.to_string())
     }
 }
@@ -1,10 +1,13 @@
-use std::io::{Error, ErrorKind};
+use std::io::Error;
 
 #[derive(Debug, Error)]
 pub enum SignatureError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("incorrect batch length: {} messages, {} public keys, {} signatures", _0, _1, _2)]
+    IncorrectBatchLength(usize, usize, usize),
+
     #[error("{}", _0)]
     Message(String),
 }
@@ -17,6 +20,6 @@ impl From<Error> for SignatureError {
 
 impl From<SignatureError> for Error {
     fn from(error: SignatureError) -> Error {
-        Error::new(ErrorKind::Other, error.to_string())
+        Error::other(error.to_string())
     }
 }
@@ -28,28 +28,32 @@ impl From<io::Error> for SynthesisError {
     }
 }
 
-impl Error for SynthesisError {
-    fn description(&self) -> &str {
-        match *self {
-            SynthesisError::AssignmentMissing => "an assignment for a variable could not be computed",
-            SynthesisError::DivisionByZero => "division by zero",
-            SynthesisError::Unsatisfiable => "unsatisfiable constraint system",
-            SynthesisError::PolynomialDegreeTooLarge => "polynomial degree is too large",
-            SynthesisError::UnexpectedIdentity => "encountered an identity element in the CRS",
-            SynthesisError::IoError(_) => "encountered an I/O error",
-            SynthesisError::MalformedVerifyingKey => "malformed verifying key",
-            SynthesisError::UnconstrainedVariable => "auxiliary variable was unconstrained",
-        }
-    }
-}
+impl Error for SynthesisError {}
 
 impl fmt::Display for SynthesisError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
-        if let &SynthesisError::IoError(ref e) = self {
-            write!(f, "I/O error: ")?;
-            e.fmt(f)
-        } else {
-            write!(f, "{}", self.to_string())
+        match self {
+            SynthesisError::AssignmentMissing => write!(f, "an assignment for a variable could not be computed"),
+            SynthesisError::DivisionByZero => write!(f, "division by zero"),
+            SynthesisError::Unsatisfiable => write!(f, "unsatisfiable constraint system"),
+            SynthesisError::PolynomialDegreeTooLarge => write!(f, "polynomial degree is too large"),
+            SynthesisError::UnexpectedIdentity => write!(f, "encountered an identity element in the CRS"),
+            SynthesisError::IoError(e) => write!(f, "I/O error: {}", e),
+            SynthesisError::MalformedVerifyingKey => write!(f, "malformed verifying key"),
+            SynthesisError::UnconstrainedVariable => write!(f, "auxiliary variable was unconstrained"),
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(SynthesisError::DivisionByZero.to_string(), "division by zero");
+
+        let error = SynthesisError::IoError(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated CRS"));
+        assert_eq!(error.to_string(), "I/O error: truncated CRS");
+    }
+}
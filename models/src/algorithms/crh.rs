@@ -1,5 +1,8 @@
 use snarkvm_errors::algorithms::CRHError;
-use snarkvm_utilities::bytes::{FromBytes, ToBytes};
+use snarkvm_utilities::{
+    bytes::{FromBytes, ToBytes},
+    to_bytes,
+};
 
 use rand::Rng;
 use std::{fmt::Debug, hash::Hash};
@@ -14,5 +17,113 @@ pub trait CRH: From<<Self as CRH>::Parameters> + Clone {
 
     fn hash(&self, input: &[u8]) -> Result<Self::Output, CRHError>;
 
+    /// Hashes `input` packed into bytes least-significant bit first, rejecting lengths that are not a multiple of 8.
+    fn hash_bits(&self, input: &[bool]) -> Result<Self::Output, CRHError> {
+        if !input.len().is_multiple_of(8) {
+            return Err(CRHError::IncorrectBitLength(input.len()));
+        }
+
+        let bytes: Vec<u8> = input
+            .chunks(8)
+            .map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | *bit as u8))
+            .collect();
+        self.hash(&bytes)
+    }
+
+    /// Hashes the concatenation of the byte encodings of the field elements in `input`.
+    /// The `ToBytes` bound is a stand-in until `PrimeField` is available in this crate.
+    fn hash_field_elements<F: ToBytes>(&self, input: &[F]) -> Result<Self::Output, CRHError> {
+        self.hash(&to_bytes![input]?)
+    }
+
     fn parameters(&self) -> &Self::Parameters;
 }
+
+/// A CRH that compresses two of its outputs into one, as used for Merkle tree inner nodes.
+pub trait TwoToOneCRH: CRH {
+    /// Hashes the concatenation of the byte encodings of `left` and `right`.
+    fn compress(&self, left: &Self::Output, right: &Self::Output) -> Result<Self::Output, CRHError> {
+        self.hash(&to_bytes![left, right]?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A 64-bit FNV-1a hash standing in for a real CRH.
+    #[derive(Clone)]
+    struct TestCRH;
+
+    impl From<()> for TestCRH {
+        fn from(_parameters: ()) -> Self {
+            TestCRH
+        }
+    }
+
+    impl CRH for TestCRH {
+        type Output = u64;
+        type Parameters = ();
+
+        const INPUT_SIZE_BITS: usize = 256;
+
+        fn setup<R: Rng>(_rng: &mut R) -> Self {
+            TestCRH
+        }
+
+        fn hash(&self, input: &[u8]) -> Result<u64, CRHError> {
+            Ok(input.iter().fold(0xcbf29ce484222325, |hash, byte| {
+                (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+            }))
+        }
+
+        fn parameters(&self) -> &() {
+            &()
+        }
+    }
+
+    impl TwoToOneCRH for TestCRH {}
+
+    #[test]
+    fn test_compress() {
+        let crh = TestCRH;
+        let left = crh.hash(b"left").unwrap();
+        let right = crh.hash(b"right").unwrap();
+
+        let expected = crh.hash(&to_bytes![left, right].unwrap()).unwrap();
+        assert_eq!(crh.compress(&left, &right).unwrap(), expected);
+        assert_ne!(crh.compress(&right, &left).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_hash_bits() {
+        let crh = TestCRH;
+        let value = 0x0123456789abcdefu64;
+
+        let bits: Vec<bool> = (0..64).map(|i| (value >> i) & 1 == 1).collect();
+        assert_eq!(
+            crh.hash_bits(&bits).unwrap(),
+            crh.hash(&to_bytes![value].unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_hash_bits_incorrect_length() {
+        let crh = TestCRH;
+
+        assert!(matches!(crh.hash_bits(&[true]), Err(CRHError::IncorrectBitLength(1))));
+        assert!(matches!(
+            crh.hash_bits(&[true, false]),
+            Err(CRHError::IncorrectBitLength(2))
+        ));
+    }
+
+    #[test]
+    fn test_hash_field_elements() {
+        let crh = TestCRH;
+        let elements = vec![1u64, 2, 3];
+
+        let expected = crh.hash(&to_bytes![elements].unwrap()).unwrap();
+        assert_eq!(crh.hash_field_elements(&elements).unwrap(), expected);
+    }
+}
@@ -32,6 +32,30 @@ pub trait SignatureScheme: Sized + Clone {
         signature: &Self::Output,
     ) -> Result<bool, SignatureError>;
 
+    /// Returns `true` if every signature in the batch verifies against its message and public key.
+    /// Returns `Err(IncorrectBatchLength)` if the slices differ in length.
+    fn batch_verify(
+        &self,
+        messages: &[&[u8]],
+        public_keys: &[Self::PublicKey],
+        signatures: &[Self::Output],
+    ) -> Result<bool, SignatureError> {
+        if messages.len() != public_keys.len() || messages.len() != signatures.len() {
+            return Err(SignatureError::IncorrectBatchLength(
+                messages.len(),
+                public_keys.len(),
+                signatures.len(),
+            ));
+        }
+
+        for ((message, public_key), signature) in messages.iter().zip(public_keys).zip(signatures) {
+            if !self.verify(public_key, message, signature)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
     fn randomize_public_key(
         &self,
         public_key: &Self::PublicKey,
@@ -40,3 +64,98 @@ pub trait SignatureScheme: Sized + Clone {
 
     fn randomize_signature(&self, signature: &Self::Output, randomness: &[u8]) -> Result<Self::Output, SignatureError>;
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use snarkvm_utilities::rand::test_rng;
+
+    /// A scheme whose signature is a running hash of the message keyed by the public key.
+    #[derive(Clone)]
+    struct TestSignature;
+
+    fn digest(key: u64, message: &[u8]) -> u64 {
+        message
+            .iter()
+            .fold(key, |hash, byte| hash.wrapping_mul(31).wrapping_add(*byte as u64))
+    }
+
+    impl SignatureScheme for TestSignature {
+        type Output = u64;
+        type Parameters = ();
+        type PrivateKey = u64;
+        type PublicKey = u64;
+
+        fn setup<R: Rng>(_rng: &mut R) -> Result<Self, SignatureError> {
+            Ok(TestSignature)
+        }
+
+        fn parameters(&self) -> &() {
+            &()
+        }
+
+        fn generate_private_key<R: Rng>(&self, rng: &mut R) -> Result<u64, SignatureError> {
+            Ok(rng.gen())
+        }
+
+        fn generate_public_key(&self, private_key: &u64) -> Result<u64, SignatureError> {
+            Ok(*private_key)
+        }
+
+        fn sign<R: Rng>(&self, private_key: &u64, message: &[u8], _rng: &mut R) -> Result<u64, SignatureError> {
+            Ok(digest(*private_key, message))
+        }
+
+        fn verify(&self, public_key: &u64, message: &[u8], signature: &u64) -> Result<bool, SignatureError> {
+            Ok(*signature == digest(*public_key, message))
+        }
+
+        fn randomize_public_key(&self, public_key: &u64, _randomness: &[u8]) -> Result<u64, SignatureError> {
+            Ok(*public_key)
+        }
+
+        fn randomize_signature(&self, signature: &u64, _randomness: &[u8]) -> Result<u64, SignatureError> {
+            Ok(*signature)
+        }
+    }
+
+    fn signed_batch() -> (TestSignature, Vec<&'static [u8]>, Vec<u64>, Vec<u64>) {
+        let rng = &mut test_rng();
+        let scheme = TestSignature::setup(rng).unwrap();
+        let messages: Vec<&'static [u8]> = vec![&b"first"[..], &b"second"[..], &b"third"[..]];
+
+        let private_keys: Vec<_> = (0..3).map(|_| scheme.generate_private_key(rng).unwrap()).collect();
+        let public_keys = private_keys
+            .iter()
+            .map(|private_key| scheme.generate_public_key(private_key).unwrap())
+            .collect();
+        let signatures = private_keys
+            .iter()
+            .zip(&messages)
+            .map(|(private_key, message)| scheme.sign(private_key, message, rng).unwrap())
+            .collect();
+
+        (scheme, messages, public_keys, signatures)
+    }
+
+    #[test]
+    fn test_batch_verify() {
+        let (scheme, messages, public_keys, signatures) = signed_batch();
+        assert!(scheme.batch_verify(&messages, &public_keys, &signatures).unwrap());
+
+        // A single bad signature at any position fails the whole batch.
+        for i in 0..signatures.len() {
+            let mut forged = signatures.clone();
+            forged[i] ^= 1;
+            assert!(!scheme.batch_verify(&messages, &public_keys, &forged).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_batch_verify_incorrect_length() {
+        let (scheme, messages, public_keys, signatures) = signed_batch();
+
+        let result = scheme.batch_verify(&messages[..2], &public_keys, &signatures);
+        assert!(matches!(result, Err(SignatureError::IncorrectBatchLength(2, 3, 3))));
+    }
+}
@@ -1,9 +1,14 @@
 use snarkvm_errors::algorithms::SNARKError;
 use snarkvm_utilities::bytes::{FromBytes, ToBytes};
 
-use rand::Rng;
+use blake2::{Blake2s, Digest};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
 use std::fmt::Debug;
 
+/// The Blake2s personalization used to derive the `setup_deterministic` RNG key.
+const SETUP_DETERMINISTIC_PERSONALIZATION: &[u8] = b"AleoSRS0";
+
 pub trait SNARK {
     type AssignedCircuit;
     type Circuit;
@@ -24,6 +29,21 @@ pub trait SNARK {
         rng: &mut R,
     ) -> Result<(Self::ProvingParameters, Self::PreparedVerificationParameters), SNARKError>;
 
+    /// Runs `setup` with a ChaCha20 RNG keyed by the personalized Blake2s hash of `seed`.
+    /// Intended for reproducing audited parameters; real deployments must use an MPC ceremony.
+    fn setup_deterministic(
+        circuit: Self::Circuit,
+        seed: [u8; 64],
+    ) -> Result<(Self::ProvingParameters, Self::PreparedVerificationParameters), SNARKError> {
+        let mut hasher = Blake2s::with_params(&[], &[], SETUP_DETERMINISTIC_PERSONALIZATION);
+        hasher.update(&seed[..]);
+
+        let mut key = [0u8; 32];
+        key.copy_from_slice(&hasher.finalize());
+
+        Self::setup(circuit, &mut ChaChaRng::from_seed(key))
+    }
+
     fn prove<R: Rng>(
         parameter: &Self::ProvingParameters,
         input_and_witness: Self::AssignedCircuit,
@@ -36,3 +56,57 @@ pub trait SNARK {
         proof: &Self::Proof,
     ) -> Result<bool, SNARKError>;
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct TestSNARK;
+
+    impl SNARK for TestSNARK {
+        type AssignedCircuit = ();
+        type Circuit = ();
+        type PreparedVerificationParameters = u64;
+        type Proof = u64;
+        type ProvingParameters = u64;
+        type VerificationParameters = u64;
+        type VerifierInput = ();
+
+        fn setup<R: Rng>(_circuit: (), rng: &mut R) -> Result<(u64, u64), SNARKError> {
+            Ok((rng.gen(), rng.gen()))
+        }
+
+        fn prove<R: Rng>(_parameter: &u64, _input_and_witness: (), rng: &mut R) -> Result<u64, SNARKError> {
+            Ok(rng.gen())
+        }
+
+        fn verify(_verifier_key: &u64, _input: &(), _proof: &u64) -> Result<bool, SNARKError> {
+            Ok(true)
+        }
+    }
+
+    fn setup(seed: [u8; 64]) -> (u64, u64) {
+        TestSNARK::setup_deterministic((), seed).unwrap()
+    }
+
+    #[test]
+    fn test_setup_deterministic() {
+        let seed = [7u8; 64];
+        assert_eq!(setup(seed), setup(seed));
+
+        let mut other_seed = seed;
+        other_seed[63] ^= 1;
+        assert_ne!(setup(seed), setup(other_seed));
+    }
+
+    #[test]
+    fn test_setup_deterministic_uses_whole_seed() {
+        assert_ne!(setup([0u8; 64]), setup([0xff; 64]));
+
+        let mut seed = [0u8; 64];
+        seed[..32].copy_from_slice(&[1u8; 32]);
+        let mut swapped_seed = [0u8; 64];
+        swapped_seed[32..].copy_from_slice(&[1u8; 32]);
+        assert_ne!(setup(seed), setup(swapped_seed));
+    }
+}
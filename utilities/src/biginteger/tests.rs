@@ -3,35 +3,36 @@ use crate::{biginteger::*, rand::UniformRand};
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
 
+#[allow(clippy::eq_op)]
 fn biginteger_arithmetic_test<B: BigInteger>(a: B, b: B, zero: B) {
     // zero == zero
     assert_eq!(zero, zero);
 
     // zero.is_zero() == true
-    assert_eq!(zero.is_zero(), true);
+    assert!(zero.is_zero());
 
     // a == a
     assert_eq!(a, a);
 
     // a + 0 = a
-    let mut a0_add = a.clone();
+    let mut a0_add = a;
     a0_add.add_nocarry(&zero);
     assert_eq!(a0_add, a);
 
     // a - 0 = a
-    let mut a0_sub = a.clone();
+    let mut a0_sub = a;
     a0_sub.sub_noborrow(&zero);
     assert_eq!(a0_sub, a);
 
     // a - a = 0
-    let mut aa_sub = a.clone();
+    let mut aa_sub = a;
     aa_sub.sub_noborrow(&a);
     assert_eq!(aa_sub, zero);
 
     // a + b = b + a
-    let mut ab_add = a.clone();
+    let mut ab_add = a;
     ab_add.add_nocarry(&b);
-    let mut ba_add = b.clone();
+    let mut ba_add = b;
     ba_add.add_nocarry(&a);
     assert_eq!(ab_add, ba_add);
 }
@@ -12,6 +12,15 @@ pub trait ToBytes {
 pub trait FromBytes: Sized {
     /// Reads `Self` from `reader`.
     fn read<R: Read>(reader: R) -> IoResult<Self>;
+
+    /// Reads `Self` from `bytes`, failing if any bytes are left over.
+    fn from_bytes_exact(mut bytes: &[u8]) -> IoResult<Self> {
+        let value = Self::read(&mut bytes)?;
+        if !bytes.is_empty() {
+            return Err(error("FromBytes::from_bytes_exact found trailing bytes"));
+        }
+        Ok(value)
+    }
 }
 
 macro_rules! array_bytes {
@@ -303,7 +312,7 @@ impl<'a, T: 'a + ToBytes> ToBytes for &'a T {
 
 #[cfg(test)]
 mod test {
-    use super::ToBytes;
+    use super::{FromBytes, ToBytes};
     use crate::Vec;
     #[test]
     fn test_macro_empty() {
@@ -327,4 +336,12 @@ mod test {
         actual_bytes.extend_from_slice(&array3);
         assert_eq!(bytes, actual_bytes);
     }
+
+    #[test]
+    fn test_from_bytes_exact() {
+        let bytes = to_bytes![1u32, 2u64].unwrap();
+        assert!(u32::from_bytes_exact(&bytes).is_err());
+        assert!(<[u8; 13]>::from_bytes_exact(&bytes).is_err());
+        assert_eq!(<[u8; 12]>::from_bytes_exact(&bytes).unwrap().to_vec(), bytes);
+    }
 }
@@ -0,0 +1,94 @@
+use std::io::{Result as IoResult, Write};
+
+/// A writer that forwards to `inner` in chunks of at most `chunk_size` bytes,
+/// counting the bytes written and reporting progress through a callback.
+///
+/// `progress` is called with the running total every time it crosses a multiple of
+/// `chunk_size`, which lets CLIs report progress while exporting large keys without
+/// buffering the serialized output.
+pub struct ChunkedWriter<W: Write, F: FnMut(usize)> {
+    inner: W,
+    chunk_size: usize,
+    bytes_written: usize,
+    progress: F,
+}
+
+impl<W: Write, F: FnMut(usize)> ChunkedWriter<W, F> {
+    /// Wraps `inner`, calling `progress` after every `chunk_size` bytes written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn new(inner: W, chunk_size: usize, progress: F) -> Self {
+        assert!(chunk_size > 0, "chunk size must be non-zero");
+
+        Self {
+            inner,
+            chunk_size,
+            bytes_written: 0,
+            progress,
+        }
+    }
+
+    /// Returns the number of bytes written to the inner writer so far.
+    pub fn bytes_written(&self) -> usize {
+        self.bytes_written
+    }
+
+    /// Returns the inner writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write, F: FnMut(usize)> Write for ChunkedWriter<W, F> {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        let remaining_in_chunk = self.chunk_size - (self.bytes_written % self.chunk_size);
+        let len = buf.len().min(remaining_in_chunk);
+
+        let written = self.inner.write(&buf[..len])?;
+        let previous_chunks = self.bytes_written / self.chunk_size;
+        self.bytes_written += written;
+
+        if self.bytes_written / self.chunk_size > previous_chunks {
+            (self.progress)(self.bytes_written);
+        }
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bytes::ToBytes;
+
+    #[test]
+    fn test_bytes_written() {
+        let values = vec![[7u64; 32]; 100];
+        let expected = to_bytes![values].unwrap();
+
+        let mut writer = ChunkedWriter::new(Vec::new(), 1000, |_| {});
+        values.write(&mut writer).unwrap();
+
+        assert_eq!(writer.bytes_written(), expected.len());
+        assert_eq!(writer.into_inner(), expected);
+    }
+
+    #[test]
+    fn test_progress_reported_per_chunk() {
+        let mut reports = vec![];
+
+        let mut writer = ChunkedWriter::new(std::io::sink(), 1024, |total| reports.push(total));
+        writer.write_all(&[0u8; 1000]).unwrap();
+        writer.write_all(&[0u8; 5000]).unwrap();
+        writer.write_all(&[0u8; 100]).unwrap();
+        assert_eq!(writer.bytes_written(), 6100);
+
+        assert_eq!(reports, vec![1024, 2048, 3072, 4096, 5120]);
+    }
+}
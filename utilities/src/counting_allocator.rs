@@ -0,0 +1,162 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static TOTAL: AtomicUsize = AtomicUsize::new(0);
+
+/// A global allocator that forwards to the system allocator and counts the bytes it hands out.
+///
+/// Register it with `#[global_allocator]` in a test binary and measure a scope with
+/// `AllocationGuard`. Intended for tests only.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            if new_size > layout.size() {
+                record_allocation(new_size - layout.size());
+            } else {
+                CURRENT.fetch_sub(layout.size() - new_size, Ordering::SeqCst);
+            }
+        }
+        new_ptr
+    }
+}
+
+fn record_allocation(size: usize) {
+    TOTAL.fetch_add(size, Ordering::SeqCst);
+    let current = CURRENT.fetch_add(size, Ordering::SeqCst) + size;
+    PEAK.fetch_max(current, Ordering::SeqCst);
+}
+
+/// Measures the allocations made through `CountingAllocator` since the guard was started.
+///
+/// The counters are process-wide, so allocations made by other threads are included, and
+/// starting a guard resets the peak counter, so guards must not be nested.
+pub struct AllocationGuard {
+    start_current: usize,
+    start_total: usize,
+}
+
+impl AllocationGuard {
+    /// Starts measuring from the current allocator state.
+    pub fn start() -> Self {
+        let start_current = CURRENT.load(Ordering::SeqCst);
+        PEAK.store(start_current, Ordering::SeqCst);
+
+        Self {
+            start_current,
+            start_total: TOTAL.load(Ordering::SeqCst),
+        }
+    }
+
+    /// Returns the largest number of bytes live at once since the guard was started,
+    /// not counting the bytes that were already live at that point.
+    pub fn peak(&self) -> usize {
+        PEAK.load(Ordering::SeqCst).saturating_sub(self.start_current)
+    }
+
+    /// Returns the number of bytes allocated since the guard was started.
+    /// A `realloc` only counts the bytes it grows the allocation by.
+    pub fn total(&self) -> usize {
+        TOTAL.load(Ordering::SeqCst) - self.start_total
+    }
+
+    /// Panics with the measured peak if it exceeds `budget` bytes.
+    pub fn assert_peak_within(&self, budget: usize) {
+        let peak = self.peak();
+        assert!(
+            peak <= budget,
+            "peak allocation of {} bytes exceeds the budget of {} bytes",
+            peak,
+            budget
+        );
+    }
+
+    /// Panics with the measured total if it exceeds `budget` bytes.
+    pub fn assert_total_within(&self, budget: usize) {
+        let total = self.total();
+        assert!(
+            total <= budget,
+            "total allocation of {} bytes exceeds the budget of {} bytes",
+            total,
+            budget
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Mutex;
+
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    fn allocate_and_free(sizes: &[usize]) {
+        let layouts: Vec<_> = sizes
+            .iter()
+            .map(|size| Layout::from_size_align(*size, 8).unwrap())
+            .collect();
+        let ptrs: Vec<_> = layouts
+            .iter()
+            .map(|layout| unsafe { CountingAllocator.alloc(*layout) })
+            .collect();
+        for (ptr, layout) in ptrs.into_iter().zip(layouts) {
+            unsafe { CountingAllocator.dealloc(ptr, layout) };
+        }
+    }
+
+    #[test]
+    fn test_guard_records_peak_and_total() {
+        let _lock = LOCK.lock().unwrap();
+
+        let guard = AllocationGuard::start();
+        allocate_and_free(&[1024, 1024]);
+        allocate_and_free(&[512]);
+
+        assert_eq!(guard.peak(), 2048);
+        assert_eq!(guard.total(), 2560);
+        guard.assert_peak_within(2048);
+        guard.assert_total_within(2560);
+    }
+
+    #[test]
+    fn test_guard_reports_measured_peak() {
+        let _lock = LOCK.lock().unwrap();
+
+        let guard = AllocationGuard::start();
+        allocate_and_free(&[4096]);
+
+        let error = std::panic::catch_unwind(|| guard.assert_peak_within(4095)).unwrap_err();
+        let message = error.downcast_ref::<String>().unwrap();
+        assert_eq!(
+            message,
+            "peak allocation of 4096 bytes exceeds the budget of 4095 bytes"
+        );
+    }
+}
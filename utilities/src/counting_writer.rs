@@ -0,0 +1,68 @@
+use crate::bytes::ToBytes;
+
+use std::io::{Result as IoResult, Write};
+
+/// A writer that discards its input and only counts the bytes written to it.
+#[derive(Debug, Default)]
+pub struct CountingWriter {
+    bytes_written: usize,
+}
+
+impl CountingWriter {
+    /// Returns the number of bytes written so far.
+    pub fn bytes_written(&self) -> usize {
+        self.bytes_written
+    }
+}
+
+impl Write for CountingWriter {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        self.bytes_written += buf.len();
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+/// Returns the length of the `ToBytes` serialization of `value`,
+/// without allocating a buffer for it.
+pub fn serialized_size<T: ToBytes>(value: &T) -> IoResult<usize> {
+    let mut writer = CountingWriter::default();
+    value.write(&mut writer)?;
+    Ok(writer.bytes_written())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::biginteger::BigInteger384;
+
+    struct Composite {
+        integers: Vec<BigInteger384>,
+        bytes: [u8; 32],
+        signed: i64,
+    }
+
+    impl ToBytes for Composite {
+        fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+            self.integers.write(&mut writer)?;
+            self.bytes.write(&mut writer)?;
+            self.signed.write(&mut writer)
+        }
+    }
+
+    #[test]
+    fn test_serialized_size() {
+        let value = Composite {
+            integers: vec![BigInteger384::from(7u64); 10],
+            bytes: [1u8; 32],
+            signed: -5,
+        };
+
+        assert_eq!(serialized_size(&value).unwrap(), to_bytes![value].unwrap().len());
+    }
+}
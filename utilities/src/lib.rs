@@ -18,6 +18,14 @@ pub mod biginteger;
 pub mod bititerator;
 #[macro_use]
 pub mod bytes;
+#[cfg(feature = "std")]
+pub mod chunked_writer;
+#[cfg(feature = "counting-allocator")]
+pub mod counting_allocator;
+#[cfg(feature = "std")]
+pub mod counting_writer;
+#[cfg(feature = "std")]
+pub mod limited_reader;
 pub mod rand;
 pub mod variable_length_integer;
 
@@ -34,7 +42,7 @@ fn error(_msg: &'static str) -> io::Error {
 
 #[cfg(feature = "std")]
 fn error(msg: &'static str) -> io::Error {
-    io::Error::new(io::ErrorKind::Other, msg)
+    io::Error::other(msg)
 }
 
 #[macro_export]
@@ -0,0 +1,108 @@
+use std::io::{Error, Read, Result as IoResult};
+
+/// A reader that allows at most `limit` bytes to be read from `inner`.
+///
+/// Once the limit is reached, a read returns `Ok(0)` if `inner` is exhausted and fails if it
+/// has more data, so a caller sees a short read before the error. Wrapping untrusted input in it bounds how much a `FromBytes` implementation will consume
+/// before giving up, instead of parsing an arbitrarily long stream.
+pub struct LimitedReader<R: Read> {
+    inner: R,
+    limit: usize,
+    bytes_read: usize,
+    probed: Option<u8>,
+}
+
+impl<R: Read> LimitedReader<R> {
+    /// Wraps `inner`, allowing at most `limit` bytes to be read from it.
+    pub fn new(inner: R, limit: usize) -> Self {
+        Self {
+            inner,
+            limit,
+            bytes_read: 0,
+            probed: None,
+        }
+    }
+
+    /// Returns the number of bytes read from the inner reader so far.
+    pub fn bytes_read(&self) -> usize {
+        self.bytes_read
+    }
+
+    /// Returns the inner reader, along with the byte read from it to detect data past the limit.
+    pub fn into_inner(self) -> (R, Option<u8>) {
+        (self.inner, self.probed)
+    }
+}
+
+impl<R: Read> Read for LimitedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let remaining = self.limit - self.bytes_read;
+        if remaining == 0 {
+            // Probe a single byte so that a stream of exactly `limit` bytes still ends cleanly.
+            // The byte is kept for `into_inner`, and later reads fail without probing again.
+            if self.probed.is_none() {
+                let mut byte = [0u8; 1];
+                if self.inner.read(&mut byte)? == 0 {
+                    return Ok(0);
+                }
+                self.probed = Some(byte[0]);
+            }
+            return Err(Error::other(format!("read limit of {} bytes exceeded", self.limit)));
+        }
+
+        let len = buf.len().min(remaining);
+        let read = self.inner.read(&mut buf[..len])?;
+        self.bytes_read += read;
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bytes::FromBytes;
+
+    #[test]
+    fn test_read_within_limit() {
+        let bytes = [3u8; 64];
+
+        let mut reader = LimitedReader::new(&bytes[..], 64);
+        let values = <[u64; 8]>::read(&mut reader).unwrap();
+
+        assert_eq!(values, [0x0303030303030303u64; 8]);
+        assert_eq!(reader.bytes_read(), 64);
+    }
+
+    #[test]
+    fn test_read_to_end_at_limit() {
+        let mut buffer = vec![];
+        let mut reader = LimitedReader::new(&[1u8, 2, 3, 4][..], 4);
+        reader.read_to_end(&mut buffer).unwrap();
+        assert_eq!(buffer, [1, 2, 3, 4]);
+
+        let mut buffer = vec![];
+        let mut reader = LimitedReader::new(&[1u8, 2, 3, 4, 5, 6][..], 4);
+        assert!(reader.read_to_end(&mut buffer).is_err());
+        assert!(reader.read(&mut [0u8; 1]).is_err());
+        assert_eq!(buffer, [1, 2, 3, 4]);
+
+        // The probed byte is returned rather than dropped, and a retry consumes nothing more.
+        let (inner, probed) = reader.into_inner();
+        assert_eq!(probed, Some(5));
+        assert_eq!(inner, [6]);
+    }
+
+    #[test]
+    fn test_read_past_limit() {
+        let mut reader = LimitedReader::new(std::io::repeat(1), 1000);
+
+        let result = (0..100).try_for_each(|_| <[u64; 32]>::read(&mut reader).map(|_| ()));
+
+        assert!(result.is_err());
+        assert_eq!(reader.bytes_read(), 1000);
+    }
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "counting-allocator")]
+
+use snarkvm_utilities::{
+    bytes::ToBytes,
+    chunked_writer::ChunkedWriter,
+    counting_allocator::{AllocationGuard, CountingAllocator},
+    counting_writer::CountingWriter,
+    to_bytes,
+};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_chunked_writer_allocation_budget() {
+    // A mid-sized key of 1 MiB.
+    let key = vec![[7u64; 32]; 4096];
+    let mut chunks = 0;
+
+    {
+        let guard = AllocationGuard::start();
+        let mut writer = ChunkedWriter::new(CountingWriter::default(), 64 * 1024, |_| chunks += 1);
+        key.write(&mut writer).unwrap();
+        assert_eq!(writer.bytes_written(), 1 << 20);
+        guard.assert_peak_within(1024);
+    }
+    assert_eq!(chunks, 16);
+
+    // Serializing into a buffer first holds the entire output in memory.
+    {
+        let guard = AllocationGuard::start();
+        let bytes = to_bytes![key].unwrap();
+        assert!(guard.peak() >= bytes.len());
+    }
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "counting-allocator")]
+
+use snarkvm_utilities::counting_allocator::{AllocationGuard, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_allocation_guard() {
+    // Growing a vector reallocates repeatedly, but only the growth is counted.
+    let capacity = {
+        let guard = AllocationGuard::start();
+        let mut values = Vec::new();
+        for i in 0..(1u32 << 16) {
+            values.push(i);
+        }
+        let capacity = values.capacity() * std::mem::size_of::<u32>();
+
+        assert!(guard.peak() >= capacity);
+        assert!(guard.total() >= capacity);
+        assert!(guard.total() < 2 * capacity, "total of {} bytes", guard.total());
+        capacity
+    };
+
+    // Freed memory lowers the live count, so a later allocation does not raise the peak.
+    {
+        let guard = AllocationGuard::start();
+        let small = vec![0u8; 1024];
+        assert!(guard.peak() >= 1024);
+        assert!(guard.peak() < capacity);
+        drop(small);
+
+        let result = std::panic::catch_unwind(|| guard.assert_total_within(0));
+        assert!(result.is_err());
+    }
+}